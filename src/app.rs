@@ -1,5 +1,6 @@
 use std::fmt::Debug;
 
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Utc};
 use components::{Route, Router, Routes};
 use leptos::logging::log;
 use leptos::{prelude::*, task::spawn_local};
@@ -17,6 +18,7 @@ pub struct ElapsedTime {
     seconds: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TimeUnit {
     Years,
     Months,
@@ -26,47 +28,171 @@ enum TimeUnit {
     Seconds,
 }
 
-impl TimeUnit {
-    fn format_timeunit(&self, value: u64) -> String {
+/// A CLDR plural category. Which category a count maps to is
+/// locale-specific (`Locale::plural_category`); which string is shown for
+/// a category is also locale-specific (`Locale::timeunit_word`). Neither
+/// German nor English uses anything but `One`/`Other`, but Polish keys off
+/// the last digit and last two digits to additionally need `Few`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PluralCategory {
+    One,
+    Few,
+    Other,
+}
+
+/// A supported UI language. Adding a locale means listing it in `ALL` and
+/// giving it a `label`, a `plural_category` rule, a full `timeunit_word`
+/// table and a `list_conjunction` below; nothing else in the app needs to
+/// change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum Locale {
+    #[default]
+    De,
+    En,
+    Pl,
+}
+
+impl Locale {
+    const ALL: [Locale; 3] = [Locale::De, Locale::En, Locale::Pl];
+
+    fn label(self) -> &'static str {
+        match self {
+            Locale::De => "Deutsch",
+            Locale::En => "English",
+            Locale::Pl => "Polski",
+        }
+    }
+
+    /// CLDR plural rule: which category does `n` fall into in this locale?
+    fn plural_category(self, n: u64) -> PluralCategory {
+        match self {
+            Locale::De | Locale::En => {
+                if n == 1 {
+                    PluralCategory::One
+                } else {
+                    PluralCategory::Other
+                }
+            }
+            // Polish: one = n == 1; few = last digit 2-4, except when the
+            // last two digits are 12-14; otherwise many (folded into
+            // `Other` here, since no unit distinguishes many from other).
+            Locale::Pl => {
+                let last_digit = n % 10;
+                let last_two_digits = n % 100;
+                if n == 1 {
+                    PluralCategory::One
+                } else if (2..=4).contains(&last_digit) && !(12..=14).contains(&last_two_digits) {
+                    PluralCategory::Few
+                } else {
+                    PluralCategory::Other
+                }
+            }
+        }
+    }
+
+    fn timeunit_word(self, unit: TimeUnit, category: PluralCategory) -> &'static str {
+        use PluralCategory::{Few, One, Other};
+        use TimeUnit::*;
+
+        match (self, unit, category) {
+            (Locale::De, Years, One) => "Jahr",
+            (Locale::De, Years, _) => "Jahre",
+            (Locale::De, Months, One) => "Monat",
+            (Locale::De, Months, _) => "Monate",
+            (Locale::De, Days, One) => "Tag",
+            (Locale::De, Days, _) => "Tage",
+            (Locale::De, Hours, One) => "Stunde",
+            (Locale::De, Hours, _) => "Stunden",
+            (Locale::De, Minutes, One) => "Minute",
+            (Locale::De, Minutes, _) => "Minuten",
+            (Locale::De, Seconds, One) => "Sekunde",
+            (Locale::De, Seconds, _) => "Sekunden",
+
+            (Locale::En, Years, One) => "year",
+            (Locale::En, Years, _) => "years",
+            (Locale::En, Months, One) => "month",
+            (Locale::En, Months, _) => "months",
+            (Locale::En, Days, One) => "day",
+            (Locale::En, Days, _) => "days",
+            (Locale::En, Hours, One) => "hour",
+            (Locale::En, Hours, _) => "hours",
+            (Locale::En, Minutes, One) => "minute",
+            (Locale::En, Minutes, _) => "minutes",
+            (Locale::En, Seconds, One) => "second",
+            (Locale::En, Seconds, _) => "seconds",
+
+            (Locale::Pl, Years, One) => "rok",
+            (Locale::Pl, Years, Few) => "lata",
+            (Locale::Pl, Years, Other) => "lat",
+            (Locale::Pl, Months, One) => "miesiąc",
+            (Locale::Pl, Months, Few) => "miesiące",
+            (Locale::Pl, Months, Other) => "miesięcy",
+            (Locale::Pl, Days, One) => "dzień",
+            (Locale::Pl, Days, _) => "dni",
+            (Locale::Pl, Hours, One) => "godzina",
+            (Locale::Pl, Hours, Few) => "godziny",
+            (Locale::Pl, Hours, Other) => "godzin",
+            (Locale::Pl, Minutes, One) => "minuta",
+            (Locale::Pl, Minutes, Few) => "minuty",
+            (Locale::Pl, Minutes, Other) => "minut",
+            (Locale::Pl, Seconds, One) => "sekunda",
+            (Locale::Pl, Seconds, Few) => "sekundy",
+            (Locale::Pl, Seconds, Other) => "sekund",
+        }
+    }
+
+    fn list_separator(self) -> &'static str {
+        ", "
+    }
+
+    fn list_conjunction(self) -> &'static str {
         match self {
-            TimeUnit::Years if value == 1 => format!("{}&nbsp;Jahr", value),
-            TimeUnit::Years => format!("{}&nbsp;Jahre", value),
-            TimeUnit::Months if value == 1 => format!("{}&nbsp;Monat", value),
-            TimeUnit::Months => format!("{}&nbsp;Monate", value),
-            TimeUnit::Days if value == 1 => format!("{}&nbsp;Tag", value),
-            TimeUnit::Days => format!("{}&nbsp;Tage", value),
-            TimeUnit::Hours if value == 1 => format!("{}&nbsp;Stunde", value),
-            TimeUnit::Hours => format!("{}&nbsp;Stunden", value),
-            TimeUnit::Minutes if value == 1 => format!("{}&nbsp;Minute", value),
-            TimeUnit::Minutes => format!("{}&nbsp;Minuten", value),
-            TimeUnit::Seconds if value == 1 => format!("{}&nbsp;Sekunde", value),
-            TimeUnit::Seconds => format!("{}&nbsp;Sekunden", value),
+            Locale::De => " und ",
+            Locale::En => " and ",
+            Locale::Pl => " i ",
         }
     }
 }
 
+impl TimeUnit {
+    fn format_timeunit(self, value: u64, locale: Locale) -> String {
+        let word = locale.timeunit_word(self, locale.plural_category(value));
+        format!("{value}&nbsp;{word}")
+    }
+}
+
 impl ElapsedTime {
-    const SECONDS_IN_YEAR: u64 = 31536000;
-    const SECONDS_IN_MONTH: u64 = 2592000;
     const SECONDS_IN_DAY: u64 = 86400;
     const SECONDS_IN_HOUR: u64 = 3600;
 
-    fn get_elapsed_time(seconds: u64) -> Self {
-        let years = seconds / Self::SECONDS_IN_YEAR;
-        let months = (seconds % Self::SECONDS_IN_YEAR) / Self::SECONDS_IN_MONTH;
-        let days =
-            ((seconds % Self::SECONDS_IN_YEAR) % Self::SECONDS_IN_MONTH) / Self::SECONDS_IN_DAY;
-        let hours = (((seconds % Self::SECONDS_IN_YEAR) % Self::SECONDS_IN_MONTH)
-            % Self::SECONDS_IN_DAY)
-            / Self::SECONDS_IN_HOUR;
-        let minutes = ((((seconds % Self::SECONDS_IN_YEAR) % Self::SECONDS_IN_MONTH)
-            % Self::SECONDS_IN_DAY)
-            % Self::SECONDS_IN_HOUR)
-            / 60;
-        let seconds = ((((seconds % Self::SECONDS_IN_YEAR) % Self::SECONDS_IN_MONTH)
-            % Self::SECONDS_IN_DAY)
-            % Self::SECONDS_IN_HOUR)
-            % 60;
+    /// Break the span between `start` and `now` (both unix epoch seconds)
+    /// down into calendar years/months plus days/hours/minutes/seconds.
+    ///
+    /// Years and months are calendar-accurate (leap years, 28-31 day
+    /// months) rather than fixed-length buckets: we take the largest whole
+    /// number of years that fits, then the largest whole number of months
+    /// that still fits, and split whatever remains as a plain duration.
+    fn get_elapsed_time(start: u64, now: u64) -> Self {
+        let start = Self::to_datetime(start);
+        let now = Self::to_datetime(now);
+
+        let total_months = (now.year() - start.year()) * 12 + now.month() as i32
+            - start.month() as i32;
+        let mut months = total_months.max(0);
+        while months > 0 && Self::add_months(start, months) > now {
+            months -= 1;
+        }
+
+        let years = (months / 12) as u64;
+        let months = (months % 12) as u64;
+
+        let anchor = Self::add_months(start, years as i32 * 12 + months as i32);
+        let remaining = (now - anchor).num_seconds().max(0) as u64;
+
+        let days = remaining / Self::SECONDS_IN_DAY;
+        let hours = (remaining % Self::SECONDS_IN_DAY) / Self::SECONDS_IN_HOUR;
+        let minutes = ((remaining % Self::SECONDS_IN_DAY) % Self::SECONDS_IN_HOUR) / 60;
+        let seconds = ((remaining % Self::SECONDS_IN_DAY) % Self::SECONDS_IN_HOUR) % 60;
 
         ElapsedTime {
             years,
@@ -78,15 +204,57 @@ impl ElapsedTime {
         }
     }
 
-    fn fmt_output(&self) -> String {
+    fn to_datetime(epoch_secs: u64) -> DateTime<Utc> {
+        Utc.timestamp_opt(epoch_secs as i64, 0)
+            .single()
+            .expect("epoch seconds in range")
+    }
+
+    /// Add whole calendar months to `date`, clamping the day-of-month to
+    /// the last valid day of the target month (e.g. Jan 31 + 1 month =
+    /// Feb 28/29, never an invalid Feb 31).
+    fn add_months(date: DateTime<Utc>, months: i32) -> DateTime<Utc> {
+        let total = date.month0() as i32 + months;
+        let year = date.year() + total.div_euclid(12);
+        let month = total.rem_euclid(12) as u32 + 1;
+        let last_day_of_month = Self::days_in_month(year, month);
+
+        Utc.with_ymd_and_hms(
+            year,
+            month,
+            date.day().min(last_day_of_month),
+            date.hour(),
+            date.minute(),
+            date.second(),
+        )
+        .single()
+        .expect("clamped day is always valid")
+    }
+
+    fn days_in_month(year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .expect("valid calendar date")
+            .pred_opt()
+            .expect("valid calendar date")
+            .day()
+    }
+
+    fn fmt_output(&self, locale: Locale) -> String {
+        let parts = [
+            TimeUnit::Years.format_timeunit(self.years, locale),
+            TimeUnit::Months.format_timeunit(self.months, locale),
+            TimeUnit::Days.format_timeunit(self.days, locale),
+            TimeUnit::Hours.format_timeunit(self.hours, locale),
+            TimeUnit::Minutes.format_timeunit(self.minutes, locale),
+            TimeUnit::Seconds.format_timeunit(self.seconds, locale),
+        ];
+        let (last, rest) = parts.split_last().expect("six time units");
         format!(
-            "{}, {}, {}, {}, {} und {}.",
-            TimeUnit::Years.format_timeunit(self.years),
-            TimeUnit::Months.format_timeunit(self.months),
-            TimeUnit::Days.format_timeunit(self.days),
-            TimeUnit::Hours.format_timeunit(self.hours),
-            TimeUnit::Minutes.format_timeunit(self.minutes),
-            TimeUnit::Seconds.format_timeunit(self.seconds)
+            "{}{}{}.",
+            rest.join(locale.list_separator()),
+            locale.list_conjunction(),
+            last
         )
     }
 }
@@ -99,6 +267,59 @@ fn current_epoch() -> u64 {
         .as_secs()
 }
 
+/// Process-wide fan-out of counter updates to every connected `/sse` client.
+///
+/// Every reset published here reaches every open stream without anyone
+/// polling `get_count`. A send with zero subscribers is not an error.
+#[cfg(feature = "ssr")]
+mod sse {
+    use std::sync::OnceLock;
+    use tokio::sync::broadcast;
+
+    static CHANNEL: OnceLock<broadcast::Sender<u64>> = OnceLock::new();
+
+    fn channel() -> &'static broadcast::Sender<u64> {
+        CHANNEL.get_or_init(|| broadcast::channel(16).0)
+    }
+
+    /// Publish a new counter value to every subscriber.
+    pub fn publish(value: u64) {
+        let _ = channel().send(value);
+    }
+
+    /// Subscribe to future counter updates, for the `/sse` HTTP route.
+    pub fn subscribe() -> broadcast::Receiver<u64> {
+        channel().subscribe()
+    }
+}
+
+/// Byte stream of `text/event-stream` frames for the `/sse` route, encoding
+/// each value with the same [`FromToStringCodec`](codee::string::FromToStringCodec)
+/// that `use_event_source` decodes on the client. Mounted by the WASI HTTP
+/// component entrypoint alongside the `/api` server-function router.
+#[cfg(feature = "ssr")]
+pub fn sse_stream() -> impl futures::Stream<Item = Vec<u8>> {
+    use codee::{string::FromToStringCodec, Encoder};
+    use tokio::sync::broadcast;
+
+    futures::stream::unfold(sse::subscribe(), |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(value) => {
+                    let Ok(encoded) = FromToStringCodec::encode(&value) else {
+                        continue;
+                    };
+                    return Some((format!("data: {encoded}\n\n").into_bytes(), rx));
+                }
+                // a slow subscriber that missed messages simply resumes from
+                // the next one; the client re-seeds via `get_count` anyway.
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    })
+}
+
 #[cfg(feature = "ssr")]
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     view! {
@@ -119,11 +340,38 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
     }
 }
 
+/// The active `Locale`, persisted across reloads via a cookie and provided
+/// through context so any descendant (e.g. `ElapsedTimeDisp`) can reactively
+/// re-render when the user switches languages.
+#[derive(Clone, Copy)]
+struct LocaleContext {
+    locale: Signal<Option<Locale>>,
+    set_locale: WriteSignal<Option<Locale>>,
+}
+
+impl LocaleContext {
+    fn get(self) -> Locale {
+        self.locale.get().unwrap_or_default()
+    }
+}
+
+fn use_locale() -> Locale {
+    use_context::<LocaleContext>()
+        .map(LocaleContext::get)
+        .unwrap_or_default()
+}
+
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
 
+    let (locale, set_locale) = use_cookie_with_options::<Locale, codee::string::JsonSerdeCodec>(
+        "social_timer_locale",
+        UseCookieOptions::default().max_age(1000 * 60 * 60 * 24 * 365),
+    );
+    provide_context(LocaleContext { locale, set_locale });
+
     let fallback = || view! { "Page not found." }.into_view();
 
     view! {
@@ -153,19 +401,30 @@ fn HomePage() -> impl IntoView {
     // Initialize the count
     let epoch = current_epoch();
 
-    // when did the last update happen (submission to server)
-    let (last_update, set_last_update) = signal(current_epoch());
+    // when did the last update happen (submission to server). `None` until
+    // either the initial fetch or the SSE stream has reported a value.
+    let (last_update, set_last_update) = signal::<Option<u64>>(None);
 
     // increasing value of the counter
     let (count, set_count) = signal::<u64>(epoch);
 
-    let last_update_resource = Resource::new(
-        move || last_update.get(),
-        |last| async move {
+    // seeds `last_update` once, before the SSE stream below connects
+    let initial_count_resource = Resource::new(
+        || (),
+        |_| async move {
             log!("Getting value via resource");
-            get_count(last).await
+            get_count(current_epoch()).await
         },
     );
+    Effect::new(move |_| {
+        if let Some(Ok(value)) = initial_count_resource.get() {
+            set_last_update.update(|lu| {
+                if lu.is_none() {
+                    *lu = Some(value);
+                }
+            });
+        }
+    });
 
     // update every second
     use_interval(1000, move || {
@@ -173,33 +432,67 @@ fn HomePage() -> impl IntoView {
         set_count(epoch);
     });
 
-
     let UseEventSourceReturn {
         ready_state, data, error, close, ..
-    } =  use_event_source::<u64, codee::string::FromToStringCodec>("http:://localhost:3000/sse");
+    } = use_event_source::<u64, codee::string::FromToStringCodec>("/sse");
+
+    // bumped every time `data` delivers a fresh SSE value, so the reconnect
+    // reseed below can tell whether it raced against one.
+    let (generation, set_generation) = signal::<u64>(0);
+
+    // every reset published by any client lands here immediately
+    Effect::new(move |_| {
+        if let Some(value) = data.get() {
+            set_generation.update(|g| *g += 1);
+            set_last_update(Some(value));
+        }
+    });
+
+    // a dropped connection reconnects on its own; re-seed from the store in
+    // case a reset happened while we were disconnected. If a genuine SSE
+    // push lands while this fetch is in flight, `generation` will have
+    // moved on by the time it resolves, and its now-stale result is
+    // discarded instead of clobbering the newer value.
+    Effect::new(move |_| {
+        if ready_state.get() == ConnectionReadyState::Open {
+            let expected_generation = generation.get_untracked();
+            spawn_local(async move {
+                if let Ok(value) = get_count(current_epoch()).await {
+                    if generation.get_untracked() == expected_generation {
+                        set_last_update(Some(value));
+                    }
+                }
+            });
+        }
+        if let Some(err) = error.get() {
+            log!("SSE connection error: {:?}", err);
+        }
+    });
+
+    on_cleanup(move || close());
 
     // click handler set last_update to now
     let on_click = move |_| {
         spawn_local(async move {
             let current_epoch = current_epoch();
             reset_count(current_epoch).await.unwrap();
-            set_last_update(current_epoch);
+            set_last_update(Some(current_epoch));
             set_count(current_epoch);
         });
     };
 
     view! {
+        <LocaleSwitcher />
         <h1 class="title">
             "Sekunden ohne "<img class="logo" src="/static/LI-Logo.png" width="15%" /> "Vorschlag"
         </h1>
         {move || {
-            match last_update_resource.get() {
-                Some(resource_result) => {
-                    let lu2 = resource_result.unwrap();
-
+            match last_update.get() {
+                Some(lu) => {
                     view! {
-                        <ElapsedTimeDisp seconds=count last_update=lu2></ElapsedTimeDisp>
+                        <ElapsedTimeDisp seconds=count last_update=lu></ElapsedTimeDisp>
                         <button on:click=on_click>"Ich habe einen Vorschlag!"</button>
+                        <RecordsPanel last_update=last_update />
                     }
                         .into_any()
                 }
@@ -211,17 +504,149 @@ fn HomePage() -> impl IntoView {
 
 #[component]
 fn ElapsedTimeDisp(seconds: ReadSignal<u64>, last_update: u64) -> impl IntoView {
-    let et = move || ElapsedTime::get_elapsed_time(seconds.get() - last_update);
-    view! { <h1 class="seconds" inner_html=move || et().fmt_output()></h1> }
+    let et = move || ElapsedTime::get_elapsed_time(last_update, seconds.get());
+    view! { <h1 class="seconds" inner_html=move || et().fmt_output(use_locale())></h1> }
+}
+
+/// Lets the user pick the active `Locale`; persisted by `App` via a cookie.
+#[component]
+fn LocaleSwitcher() -> impl IntoView {
+    let ctx = use_context::<LocaleContext>().expect("LocaleContext provided by App");
+
+    view! {
+        <select
+            class="locale-switcher"
+            on:change=move |ev| {
+                let value = event_target_value(&ev);
+                let locale = Locale::ALL.into_iter().find(|l| l.label() == value);
+                (ctx.set_locale)(locale);
+            }
+        >
+            {Locale::ALL
+                .into_iter()
+                .map(|l| {
+                    let selected = l == ctx.get();
+                    view! {
+                        <option value=l.label() selected=selected>
+                            {l.label()}
+                        </option>
+                    }
+                })
+                .collect_view()}
+        </select>
+    }
+}
+
+/// Shows the all-time longest streak and the last few completed streaks.
+///
+/// `last_update` is the same signal `HomePage` feeds from the user's own
+/// click and from the SSE stream; using it as the resource source means a
+/// streak ending — locally or on another client — refetches the panel
+/// immediately instead of waiting for a page reload.
+#[component]
+fn RecordsPanel(last_update: ReadSignal<Option<u64>>) -> impl IntoView {
+    let records = Resource::new(move || last_update.get(), |_| async move { get_records().await });
+    let history = Resource::new(move || last_update.get(), |_| async move { get_history().await });
+
+    // anchored at the streak's real `started`/`ended` timestamps, not a
+    // fake start at the Unix epoch — months/years are irregular lengths,
+    // so decomposing the raw `duration_secs` against the wrong start date
+    // gives a different (wrong) breakdown than the real span would.
+    let fmt_streak = |streak: &Streak| {
+        ElapsedTime::get_elapsed_time(streak.started, streak.ended).fmt_output(use_locale())
+    };
+
+    view! {
+        <div class="records">
+            <h2>"Rekord"</h2>
+            {move || match records.get() {
+                Some(Ok(Some(record))) => {
+                    view! { <p>{fmt_streak(&record)}</p> }.into_any()
+                }
+                Some(Ok(None)) => view! { <p>"Noch kein abgeschlossener Streak."</p> }.into_any(),
+                Some(Err(_)) | None => ().into_any(),
+            }}
+            <h2>"Letzte Streaks"</h2>
+            {move || match history.get() {
+                Some(Ok(streaks)) => {
+                    view! {
+                        <ul>
+                            {streaks
+                                .into_iter()
+                                .rev()
+                                .map(|streak| view! { <li>{fmt_streak(&streak)}</li> })
+                                .collect_view()}
+                        </ul>
+                    }
+                        .into_any()
+                }
+                Some(Err(_)) | None => ().into_any(),
+            }}
+        </div>
+    }
 }
 
+/// How long a suggestion's text is allowed to be.
+const MAX_SUGGESTION_LEN: usize = 500;
+
 #[component]
 fn Submit() -> impl IntoView {
+    let (author, set_author) = signal(String::new());
+    let (text, set_text) = signal(String::new());
+    let (status, set_status) = signal::<Option<Result<String, String>>>(None);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+
+        let trimmed = text.get_untracked().trim().to_string();
+        if trimmed.is_empty() {
+            set_status(Some(Err("Bitte einen Vorschlag eingeben.".to_string())));
+            return;
+        }
+        if trimmed.chars().count() > MAX_SUGGESTION_LEN {
+            set_status(Some(Err(format!(
+                "Vorschlag darf höchstens {MAX_SUGGESTION_LEN} Zeichen lang sein."
+            ))));
+            return;
+        }
+
+        let author = author.get_untracked();
+        spawn_local(async move {
+            match submit_suggestion(author, trimmed).await {
+                Ok(_) => {
+                    set_status(Some(Ok("Danke für deinen Vorschlag!".to_string())));
+                    set_text(String::new());
+                }
+                Err(e) => set_status(Some(Err(e.to_string()))),
+            }
+        });
+    };
+
     view! {
         <div class="dialog">
-            <h1>"Submit"</h1>
-            <input type="text" />
-            <input type="text" />
+            <h1>"Vorschlag einreichen"</h1>
+            <form on:submit=on_submit>
+                <input
+                    type="text"
+                    placeholder="Dein Name (optional)"
+                    prop:value=move || author.get()
+                    on:input=move |ev| set_author(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    placeholder="Dein Vorschlag"
+                    prop:value=move || text.get()
+                    on:input=move |ev| set_text(event_target_value(&ev))
+                />
+                <button type="submit">"Absenden"</button>
+            </form>
+            {move || {
+                match status.get() {
+                    Some(Ok(msg)) => view! { <p class="status-success">{msg}</p> }.into_any(),
+                    Some(Err(msg)) => view! { <p class="status-error">{msg}</p> }.into_any(),
+                    None => ().into_any(),
+                }
+            }}
         </div>
     }
 }
@@ -279,12 +704,216 @@ pub async fn get_count(ep: u64) -> Result<u64, ServerFnError<String>> {
 pub async fn reset_count(counter: u64) -> Result<u64, ServerFnError<String>> {
     log!("Resetting value on server");
     let store = spin_sdk::key_value::Store::open_default().map_err(|e| e.to_string())?;
+
+    let previous = store
+        .get_json::<u64>("social_timer_count")
+        .map_err(|e| e.to_string())?;
+
     store
         .set_json("social_timer_count", &counter)
         .map_err(|e| ServerFnError::ServerError(e.to_string()))?;
+
+    if let Some(previous) = previous {
+        if counter > previous {
+            record_streak(&store, previous, counter).map_err(ServerFnError::ServerError)?;
+        }
+    }
+
+    sse::publish(counter);
     Ok(counter)
 }
 
+/// How many past streaks to keep in `social_timer_history`.
+const MAX_HISTORY_LEN: usize = 50;
+
+/// A completed streak: how long the counter ran before it was reset.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Streak {
+    pub started: u64,
+    pub ended: u64,
+    pub duration_secs: u64,
+}
+
+/// How many attempts `update_json_with_retry` makes before giving up.
+#[cfg(feature = "ssr")]
+const MAX_CAS_ATTEMPTS: usize = 10;
+
+/// Read-modify-write `key`, retrying `apply` against the latest value if a
+/// concurrent writer raced us between the read and the write.
+///
+/// `spin_sdk`'s key-value store has no compare-and-swap, so we simulate one:
+/// after writing, read the value back and check it still matches what we
+/// just wrote. If another writer snuck in between our read and our write,
+/// the read-back won't match, and we retry `apply` against that writer's
+/// result instead of silently discarding it. Used by `record_streak` and
+/// `submit_suggestion`, both of which otherwise do a plain read-modify-write
+/// on a shared list that two concurrent submissions could clobber.
+#[cfg(feature = "ssr")]
+fn update_json_with_retry<T, F>(
+    store: &spin_sdk::key_value::Store,
+    key: &str,
+    mut apply: F,
+) -> Result<T, String>
+where
+    T: Clone + PartialEq + serde::Serialize + serde::de::DeserializeOwned,
+    F: FnMut(Option<T>) -> T,
+{
+    for _ in 0..MAX_CAS_ATTEMPTS {
+        let current = store.get_json::<T>(key).map_err(|e| e.to_string())?;
+        let candidate = apply(current);
+        store
+            .set_json(key, &candidate)
+            .map_err(|e| e.to_string())?;
+
+        let after_write = store.get_json::<T>(key).map_err(|e| e.to_string())?;
+        if after_write.as_ref() == Some(&candidate) {
+            return Ok(candidate);
+        }
+    }
+
+    Err(format!(
+        "could not update \"{key}\" after {MAX_CAS_ATTEMPTS} attempts due to concurrent writers"
+    ))
+}
+
+/// Append the just-ended streak to the bounded history, and update the
+/// "longest streak" record if it was beaten.
+#[cfg(feature = "ssr")]
+fn record_streak(store: &spin_sdk::key_value::Store, started: u64, ended: u64) -> Result<(), String> {
+    let streak = Streak {
+        started,
+        ended,
+        duration_secs: ended - started,
+    };
+
+    update_json_with_retry(store, "social_timer_history", |current: Option<Vec<Streak>>| {
+        let mut history = current.unwrap_or_default();
+        history.push(streak.clone());
+        if history.len() > MAX_HISTORY_LEN {
+            let overflow = history.len() - MAX_HISTORY_LEN;
+            history.drain(0..overflow);
+        }
+        history
+    })?;
+
+    update_json_with_retry(store, "social_timer_longest_streak", |current: Option<Streak>| {
+        match current {
+            Some(longest) if longest.duration_secs >= streak.duration_secs => longest,
+            _ => streak.clone(),
+        }
+    })?;
+
+    Ok(())
+}
+
+/// Get the all-time longest streak, if any streak has ended yet.
+#[server(prefix = "/api")]
+pub async fn get_records() -> Result<Option<Streak>, ServerFnError<String>> {
+    let store = spin_sdk::key_value::Store::open_default().map_err(|e| e.to_string())?;
+    store
+        .get_json::<Streak>("social_timer_longest_streak")
+        .map_err(|e| ServerFnError::ServerError(e.to_string()))
+}
+
+/// List past streaks, oldest first, most recent `MAX_HISTORY_LEN` only.
+#[server(prefix = "/api")]
+pub async fn get_history() -> Result<Vec<Streak>, ServerFnError<String>> {
+    let store = spin_sdk::key_value::Store::open_default().map_err(|e| e.to_string())?;
+    Ok(store
+        .get_json::<Vec<Streak>>("social_timer_history")
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default())
+}
+
+/// A suggestion submitted through the `/submit` form.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Suggestion {
+    pub id: u64,
+    pub author: String,
+    pub text: String,
+    pub submitted_at: u64,
+}
+
+/// Submit a new suggestion, storing it alongside all previous ones.
+///
+/// Submitting a suggestion is exactly the event the timer measures, so this
+/// also resets the counter. The suggestion is written first and is
+/// considered submitted regardless of what happens next: a failure to
+/// reset the counter is logged but does not fail the request, since the
+/// two are independent KV writes and the suggestion must not be lost (or
+/// end up duplicated by a client retry) over something that isn't its
+/// fault.
+#[server(prefix = "/api")]
+pub async fn submit_suggestion(
+    author: String,
+    text: String,
+) -> Result<Suggestion, ServerFnError<String>> {
+    // Kept in German to match the client-side validation messages in
+    // `Submit` (and the rest of the UI's copy) until server functions can
+    // be handed the caller's `Locale`.
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(ServerFnError::ServerError(
+            "Vorschlag darf nicht leer sein.".to_string(),
+        ));
+    }
+    if text.chars().count() > MAX_SUGGESTION_LEN {
+        return Err(ServerFnError::ServerError(format!(
+            "Vorschlag darf höchstens {MAX_SUGGESTION_LEN} Zeichen lang sein."
+        )));
+    }
+
+    let store = spin_sdk::key_value::Store::open_default().map_err(|e| e.to_string())?;
+
+    let author = author.trim();
+    let author = if author.is_empty() {
+        "Anonym".to_string()
+    } else {
+        author.to_string()
+    };
+    let text = text.to_string();
+    let submitted_at = current_epoch();
+
+    // `id` is assigned from the list length, so it (and the list itself)
+    // must be recomputed on every retry against whatever the latest writer
+    // left behind, not just appended to our own possibly-stale copy.
+    let mut stored = None;
+    update_json_with_retry(
+        &store,
+        "social_timer_suggestions",
+        |current: Option<Vec<Suggestion>>| {
+            let mut suggestions = current.unwrap_or_default();
+            let suggestion = Suggestion {
+                id: suggestions.len() as u64 + 1,
+                author: author.clone(),
+                text: text.clone(),
+                submitted_at,
+            };
+            stored = Some(suggestion.clone());
+            suggestions.push(suggestion);
+            suggestions
+        },
+    )
+    .map_err(ServerFnError::ServerError)?;
+    let suggestion = stored.expect("update_json_with_retry always calls `apply` at least once");
+
+    if let Err(e) = reset_count(suggestion.submitted_at).await {
+        log!("Suggestion stored but failed to reset counter: {:?}", e);
+    }
+
+    Ok(suggestion)
+}
+
+/// List all suggestions submitted so far, oldest first.
+#[server(prefix = "/api")]
+pub async fn list_suggestions() -> Result<Vec<Suggestion>, ServerFnError<String>> {
+    let store = spin_sdk::key_value::Store::open_default().map_err(|e| e.to_string())?;
+    Ok(store
+        .get_json::<Vec<Suggestion>>("social_timer_suggestions")
+        .map_err(|e| e.to_string())?
+        .unwrap_or_default())
+}
+
 /// Hook to wrap the underlying `setInterval` call and make it reactive w.r.t.
 /// possible changes of the timer interval.
 pub fn use_interval<T, F>(interval_millis: T, f: F)
@@ -311,3 +940,94 @@ where
         .expect("could not create interval")
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).single().unwrap()
+    }
+
+    fn epoch(year: i32, month: u32, day: u32) -> u64 {
+        ymd(year, month, day).timestamp() as u64
+    }
+
+    #[test]
+    fn get_elapsed_time_borrows_a_month_when_the_day_of_month_precedes_start() {
+        // Jan 31 -> Mar 1 is two calendar months by month-number alone, but
+        // day 31 doesn't exist in February, so the real span is 1 month and
+        // 1 day, not 2 months.
+        let elapsed = ElapsedTime::get_elapsed_time(epoch(2023, 1, 31), epoch(2023, 3, 1));
+        assert_eq!(elapsed.years, 0);
+        assert_eq!(elapsed.months, 1);
+        assert_eq!(elapsed.days, 1);
+        assert_eq!(elapsed.hours, 0);
+        assert_eq!(elapsed.minutes, 0);
+        assert_eq!(elapsed.seconds, 0);
+    }
+
+    #[test]
+    fn get_elapsed_time_handles_a_leap_day_start() {
+        // starting on a leap day and ending just past a non-leap February a
+        // year later must borrow the same way `add_months` does.
+        let elapsed = ElapsedTime::get_elapsed_time(epoch(2024, 2, 29), epoch(2025, 3, 1));
+        assert_eq!(elapsed.years, 1);
+        assert_eq!(elapsed.months, 0);
+        assert_eq!(elapsed.days, 1);
+        assert_eq!(elapsed.hours, 0);
+        assert_eq!(elapsed.minutes, 0);
+        assert_eq!(elapsed.seconds, 0);
+    }
+
+    #[test]
+    fn add_months_clamps_day_to_end_of_month() {
+        // Jan 31 + 1 month: February never has a 31st.
+        assert_eq!(ElapsedTime::add_months(ymd(2023, 1, 31), 1), ymd(2023, 2, 28));
+        // same, but in a leap year.
+        assert_eq!(ElapsedTime::add_months(ymd(2024, 1, 31), 1), ymd(2024, 2, 29));
+    }
+
+    #[test]
+    fn add_months_handles_leap_year_boundary() {
+        // Feb 29 + 1 year lands outside a leap year and must clamp.
+        assert_eq!(ElapsedTime::add_months(ymd(2024, 2, 29), 12), ymd(2025, 2, 28));
+        // Feb 29 + 4 years lands back on a leap year and keeps the day.
+        assert_eq!(ElapsedTime::add_months(ymd(2024, 2, 29), 48), ymd(2028, 2, 29));
+    }
+
+    #[test]
+    fn add_months_rolls_over_the_year() {
+        assert_eq!(ElapsedTime::add_months(ymd(2023, 12, 15), 1), ymd(2024, 1, 15));
+        assert_eq!(ElapsedTime::add_months(ymd(2023, 11, 30), 14), ymd(2025, 1, 30));
+    }
+
+    #[test]
+    fn plural_category_de_en_only_distinguish_one_and_other() {
+        assert_eq!(Locale::De.plural_category(1), PluralCategory::One);
+        for n in [0, 2, 5, 21, 100] {
+            assert_eq!(Locale::De.plural_category(n), PluralCategory::Other);
+        }
+        assert_eq!(Locale::En.plural_category(1), PluralCategory::One);
+        assert_eq!(Locale::En.plural_category(2), PluralCategory::Other);
+    }
+
+    #[test]
+    fn plural_category_pl_few_excludes_the_teens() {
+        // last digit 2-4 => few, unless the last two digits are 12-14.
+        for n in [2, 3, 4, 22, 23, 24, 102] {
+            assert_eq!(Locale::Pl.plural_category(n), PluralCategory::Few, "n={n}");
+        }
+        for n in [1, 12, 13, 14, 112, 5, 11, 100] {
+            let expected = if n == 1 { PluralCategory::One } else { PluralCategory::Other };
+            assert_eq!(Locale::Pl.plural_category(n), expected, "n={n}");
+        }
+    }
+
+    #[test]
+    fn timeunit_word_pl_uses_the_right_category() {
+        assert_eq!(Locale::Pl.timeunit_word(TimeUnit::Years, PluralCategory::One), "rok");
+        assert_eq!(Locale::Pl.timeunit_word(TimeUnit::Years, PluralCategory::Few), "lata");
+        assert_eq!(Locale::Pl.timeunit_word(TimeUnit::Years, PluralCategory::Other), "lat");
+    }
+}